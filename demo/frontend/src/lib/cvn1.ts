@@ -216,6 +216,36 @@ export async function canMint(collectionAddr: string): Promise<boolean> {
     }
 }
 
+// ============================================
+// Token Metadata View
+// ============================================
+
+export interface TokenMetadata {
+    name: string;
+    description: string;
+    uri: string;
+}
+
+// Get token metadata (name, description, uri)
+export async function getTokenMetadata(nftAddr: string): Promise<TokenMetadata | null> {
+    try {
+        const result = await cedra.view({
+            payload: {
+                function: `${CVN1_ADDRESS}::vault_views::get_token_metadata`,
+                typeArguments: [],
+                functionArguments: [nftAddr],
+            },
+        });
+        return {
+            name: result[0] as string,
+            description: result[1] as string,
+            uri: result[2] as string,
+        };
+    } catch {
+        return null;
+    }
+}
+
 // ============================================
 // Transaction Payload Builders
 // ============================================
@@ -247,7 +277,7 @@ export function buildInitCollectionPayload(
             mintPriceFa,
             allowedAssets,
             creatorPayoutAddr,
-            maxSupply,  // v4
+            maxSupply.toString(),  // v4
         ],
     };
 }
@@ -266,6 +296,59 @@ export function buildPublicMintPayload(
     };
 }
 
+// Get the minted NFT object address from a mint tx (any mint variant)
+// Reads nft_object_addr from the VaultedNFTMinted event
+export async function getMintedNftAddrFromTx(txHash: string): Promise<string | null> {
+    try {
+        const txn = await cedra.waitForTransaction({ transactionHash: txHash });
+        const events = (txn as Record<string, unknown>).events as Array<Record<string, unknown>>;
+
+        for (const event of events) {
+            if (event.type &&
+                isMoveType(event.type as string, CVN1_ADDRESS, "vault_events::VaultedNFTMinted")) {
+                const data = event.data as Record<string, unknown>;
+                return data.nft_object_addr as string;
+            }
+        }
+        return null;
+    } catch (e) {
+        console.error("Failed to parse tx for minted NFT addr:", e);
+        return null;
+    }
+}
+
+// Creator mints to themselves (single signer, no payment)
+export function buildCreatorSelfMintPayload(
+    collectionAddr: string,
+    name: string,
+    description: string,
+    uri: string,
+    isRedeemable: boolean
+) {
+    return {
+        function: `${CVN1_ADDRESS}::minting::creator_self_mint`,
+        typeArguments: [],
+        functionArguments: [collectionAddr, name, description, uri, isRedeemable],
+    };
+}
+
+// Creator mints to `to`, charging the mint price from buyer
+// Multi-agent: creator is the sender, buyer must be added as a secondary signer
+export function buildCreatorMintPayload(
+    collectionAddr: string,
+    to: string,
+    name: string,
+    description: string,
+    uri: string,
+    isRedeemable: boolean
+) {
+    return {
+        function: `${CVN1_ADDRESS}::minting::creator_mint_vaulted_nft`,
+        typeArguments: [],
+        functionArguments: [collectionAddr, to, name, description, uri, isRedeemable],
+    };
+}
+
 export function buildDepositToCorePayload(
     nftAddr: string,
     faMetadataAddr: string,
@@ -306,6 +389,44 @@ export function buildBurnAndRedeemPayload(nftAddr: string) {
     };
 }
 
+// ============================================
+// Wallet Payload Serialization
+// ============================================
+
+export type WalletArg = string | WalletArg[];
+
+export interface EntryPayload {
+    function: string;
+    typeArguments: string[];
+    functionArguments: unknown[];
+}
+
+export interface WalletPayload {
+    function: string;
+    typeArguments: string[];
+    functionArguments: WalletArg[];
+}
+
+function serializeWalletArg(arg: unknown): WalletArg {
+    if (Array.isArray(arg)) return arg.map(serializeWalletArg);
+    if (typeof arg === "string") return arg;
+    if (typeof arg === "boolean" || typeof arg === "number" || typeof arg === "bigint") {
+        return arg.toString();
+    }
+    throw new Error(`Unsupported payload argument: ${String(arg)}`);
+}
+
+// Serialize any build*Payload result to wallet-standard JSON with every
+// argument as a string (bool -> "true"/"false", u16/u64 -> decimal,
+// vector<address> -> string[]), safe to JSON.stringify and hand to a wallet
+export function serializeWalletPayload(payload: EntryPayload): WalletPayload {
+    return {
+        function: payload.function,
+        typeArguments: payload.typeArguments.map(String),
+        functionArguments: payload.functionArguments.map(serializeWalletArg),
+    };
+}
+
 // ============================================
 // NFT & Collection Helpers
 // ============================================
@@ -361,7 +482,20 @@ export async function getVaultedCollections(): Promise<string[]> {
     }
 }
 
-export async function getCollectionDetails(addr: string): Promise<{ name: string; uri: string } | null> {
+export interface CollectionDetails {
+    name: string;
+    description: string;
+    uri: string;
+}
+
+export interface CollectionRoyalty {
+    royaltyBps: number;
+    royaltyPayee: string;  // 0x0 if no royalty set
+}
+
+export interface CollectionMetadata extends CollectionDetails, CollectionSupply, CollectionRoyalty {}
+
+export async function getCollectionDetails(addr: string): Promise<CollectionDetails | null> {
     try {
         const resource = await cedra.getAccountResource({
             accountAddress: addr,
@@ -370,6 +504,7 @@ export async function getCollectionDetails(addr: string): Promise<{ name: string
 
         return {
             name: (resource as Record<string, unknown>).name as string,
+            description: (resource as Record<string, unknown>).description as string,
             uri: (resource as Record<string, unknown>).uri as string,
         };
     } catch {
@@ -377,6 +512,50 @@ export async function getCollectionDetails(addr: string): Promise<{ name: string
     }
 }
 
+// True only for "resource not found" API errors (not timeouts, 5xx, rate limits)
+function isResourceNotFound(e: unknown): boolean {
+    const err = e as { data?: { error_code?: string } } | null;
+    return err?.data?.error_code === "resource_not_found";
+}
+
+// Get framework royalty set on the collection (v5), converted to bps
+// Collections created with creator_royalty_bps = 0 have no Royalty resource,
+// reported as { royaltyBps: 0, royaltyPayee: "0x0" }. Returns null on any other error.
+export async function getCollectionRoyalty(addr: string): Promise<CollectionRoyalty | null> {
+    try {
+        const resource = await cedra.getAccountResource({
+            accountAddress: addr,
+            resourceType: "0x4::royalty::Royalty",
+        });
+        const data = resource as Record<string, unknown>;
+        const numerator = BigInt(data.numerator as string);
+        const denominator = BigInt(data.denominator as string);
+
+        return {
+            royaltyBps: denominator > BigInt(0) ? Number((numerator * BigInt(10000)) / denominator) : 0,
+            royaltyPayee: data.payee_address as string,
+        };
+    } catch (e) {
+        if (isResourceNotFound(e)) {
+            return { royaltyBps: 0, royaltyPayee: "0x0" };
+        }
+        console.warn("Failed to get collection royalty for", addr, e);
+        return null;
+    }
+}
+
+// Get collection name/description/uri, supply and royalty in one call
+// Built from framework resources + existing views, so it works against the deployed module
+export async function getCollectionMetadata(addr: string): Promise<CollectionMetadata | null> {
+    const [details, supply, royalty] = await Promise.all([
+        getCollectionDetails(addr),
+        getCollectionSupply(addr),
+        getCollectionRoyalty(addr),
+    ]);
+    if (!details || !supply || !royalty) return null;
+    return { ...details, ...supply, ...royalty };
+}
+
 export async function getCollectionAddrFromTx(txHash: string): Promise<string | null> {
     try {
         const txn = await cedra.waitForTransaction({ transactionHash: txHash });
@@ -387,7 +566,7 @@ export async function getCollectionAddrFromTx(txHash: string): Promise<string |
                 const data = change.data as Record<string, unknown>;
 
                 if (data && data.type &&
-                    (data.type as string).includes(`${CVN1_ADDRESS}::vault_core::VaultedCollectionConfig`)) {
+                    isMoveType(data.type as string, CVN1_ADDRESS, "vault_core::VaultedCollectionConfig")) {
                     return change.address as string;
                 }
             }
@@ -407,6 +586,44 @@ export function bpsToPercent(bps: number): number {
     return bps / 100;
 }
 
+// Strict address check: "0x" followed by 1-64 hex chars (short or padded form)
+export function isValidAddress(address: string): boolean {
+    return /^0x[0-9a-fA-F]{1,64}$/.test(address);
+}
+
+// Pad to the canonical 64-hex-char form ("0x1" -> "0x000...001")
+// Throws if the input fails isValidAddress
+export function normalizeAddress(address: string): string {
+    if (!isValidAddress(address)) {
+        throw new Error(`Invalid address: ${address}`);
+    }
+    return `0x${address.slice(2).toLowerCase().padStart(64, '0')}`;
+}
+
+// Strip leading zeros to the short form ("0x000...001" -> "0x1", zero -> "0x0")
+// Throws if the input fails isValidAddress
+export function toShortAddress(address: string): string {
+    if (!isValidAddress(address)) {
+        throw new Error(`Invalid address: ${address}`);
+    }
+    const hex = address.slice(2).toLowerCase().replace(/^0+/, '');
+    return `0x${hex || '0'}`;
+}
+
+// Compare addresses ignoring short/padded form and case
+export function addressesEqual(a: string, b: string): boolean {
+    if (!isValidAddress(a) || !isValidAddress(b)) return false;
+    return normalizeAddress(a) === normalizeAddress(b);
+}
+
+// Match a fully-qualified Move type ("<addr>::module::Struct") against
+// a module address, regardless of how the node formats the address
+export function isMoveType(type: string, moduleAddr: string, name: string): boolean {
+    const sep = type.indexOf("::");
+    if (sep < 0) return false;
+    return type.slice(sep + 2) === name && addressesEqual(type.slice(0, sep), moduleAddr);
+}
+
 export function formatAddress(address: string): string {
     if (address.length <= 12) return address;
     return `${address.slice(0, 6)}...${address.slice(-4)}`;